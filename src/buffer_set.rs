@@ -1,16 +1,36 @@
 pub struct BufferSet {
     index_buffer: wgpu::Buffer,
     vertex_buffer: wgpu::Buffer,
+    index_format: wgpu::IndexFormat,
+    instance_buffer: Option<wgpu::Buffer>,
+    texture_bind_group: Option<wgpu::BindGroup>,
 }
 
 impl BufferSet {
-    pub fn new(index_buffer: wgpu::Buffer, vertex_buffer: wgpu::Buffer) -> Self {
+    pub fn new(
+        index_buffer: wgpu::Buffer,
+        vertex_buffer: wgpu::Buffer,
+        index_format: wgpu::IndexFormat,
+    ) -> Self {
         Self {
             index_buffer,
             vertex_buffer,
+            index_format,
+            instance_buffer: None,
+            texture_bind_group: None,
         }
     }
 
+    pub fn with_instance_buffer(mut self, instance_buffer: wgpu::Buffer) -> Self {
+        self.instance_buffer = Some(instance_buffer);
+        self
+    }
+
+    pub fn with_texture_bind_group(mut self, texture_bind_group: wgpu::BindGroup) -> Self {
+        self.texture_bind_group = Some(texture_bind_group);
+        self
+    }
+
     pub fn vertex_buffer(&self) -> &wgpu::Buffer {
         &self.vertex_buffer
     }
@@ -18,4 +38,16 @@ impl BufferSet {
     pub fn index_buffer(&self) -> &wgpu::Buffer {
         &self.index_buffer
     }
+
+    pub fn index_format(&self) -> wgpu::IndexFormat {
+        self.index_format
+    }
+
+    pub fn instance_buffer(&self) -> Option<&wgpu::Buffer> {
+        self.instance_buffer.as_ref()
+    }
+
+    pub fn texture_bind_group(&self) -> Option<&wgpu::BindGroup> {
+        self.texture_bind_group.as_ref()
+    }
 }
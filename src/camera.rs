@@ -28,7 +28,9 @@ impl Camera {
     }
 
     pub fn projection_matrix(&self, aspect_ratio: f32) -> Mat4 {
-        Mat4::perspective_rh_gl(self.fov_y, aspect_ratio, self.near, self.far)
+        // wgpu (like Vulkan/DX/Metal) expects NDC depth in [0, 1], not OpenGL's
+        // [-1, 1], so use the `_rh` variant rather than `_rh_gl`.
+        Mat4::perspective_rh(self.fov_y, aspect_ratio, self.near, self.far)
     }
 
     pub fn view_projection_matrix(&self, aspect_ratio: f32) -> Mat4 {
@@ -43,3 +45,39 @@ impl Camera {
         self.rotation = rotation * self.rotation;
     }
 }
+
+/// GPU layout for the camera uniform: the view-projection matrix plus the
+/// camera's world position, needed by the fragment shader's specular term.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CameraUniform {
+    pub view_proj: [[f32; 4]; 4],
+    pub view_position: [f32; 4],
+}
+
+impl CameraUniform {
+    pub fn new(camera: &Camera, aspect_ratio: f32) -> Self {
+        Self {
+            view_proj: camera.view_projection_matrix(aspect_ratio).to_cols_array_2d(),
+            view_position: camera.translation.extend(1.0).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec4;
+
+    #[test]
+    fn projection_matrix_maps_near_and_far_to_wgpu_ndc_range() {
+        let camera = Camera::default();
+        let proj = camera.projection_matrix(1.0);
+
+        let near_clip = proj * Vec4::new(0.0, 0.0, -camera.near, 1.0);
+        let far_clip = proj * Vec4::new(0.0, 0.0, -camera.far, 1.0);
+
+        assert!((near_clip.z / near_clip.w).abs() < 1e-5);
+        assert!(((far_clip.z / far_clip.w) - 1.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,89 @@
+use glam::{Mat4, Vec3, Vec4};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>) -> Self {
+        let mut min = Vec3::splat(f32::MAX);
+        let mut max = Vec3::splat(f32::MIN);
+
+        for point in points {
+            min = min.min(point);
+            max = max.max(point);
+        }
+
+        Self { min, max }
+    }
+
+    /// Transforms this AABB by `transform` and returns the new axis-aligned
+    /// bounding box of its (now possibly rotated) corners.
+    pub fn transformed(&self, transform: Mat4) -> Self {
+        let corners = [
+            Vec3::new(self.min.x, self.min.y, self.min.z),
+            Vec3::new(self.max.x, self.min.y, self.min.z),
+            Vec3::new(self.min.x, self.max.y, self.min.z),
+            Vec3::new(self.max.x, self.max.y, self.min.z),
+            Vec3::new(self.min.x, self.min.y, self.max.z),
+            Vec3::new(self.max.x, self.min.y, self.max.z),
+            Vec3::new(self.min.x, self.max.y, self.max.z),
+            Vec3::new(self.max.x, self.max.y, self.max.z),
+        ];
+
+        Self::from_points(
+            corners
+                .into_iter()
+                .map(|corner| transform.transform_point3(corner)),
+        )
+    }
+}
+
+/// The six view-frustum planes, extracted from a combined view-projection
+/// matrix, used to cull meshes whose bounding box lies entirely outside.
+pub struct Frustum {
+    planes: [Vec4; 6],
+}
+
+impl Frustum {
+    pub fn from_view_proj(view_proj: Mat4) -> Self {
+        let m = view_proj.transpose();
+        let row0 = m.x_axis;
+        let row1 = m.y_axis;
+        let row2 = m.z_axis;
+        let row3 = m.w_axis;
+
+        let planes = [
+            row3 + row0, // left
+            row3 - row0, // right
+            row3 + row1, // bottom
+            row3 - row1, // top
+            row3 + row2, // near
+            row3 - row2, // far
+        ]
+        .map(Self::normalize_plane);
+
+        Self { planes }
+    }
+
+    fn normalize_plane(plane: Vec4) -> Vec4 {
+        let length = plane.truncate().length();
+        if length > 0.0 { plane / length } else { plane }
+    }
+
+    /// "Positive vertex" test: the AABB is outside the frustum if, for any
+    /// plane, even its corner furthest along the plane's normal is behind it.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let positive = Vec3::new(
+                if plane.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                if plane.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                if plane.z >= 0.0 { aabb.max.z } else { aabb.min.z },
+            );
+
+            plane.truncate().dot(positive) + plane.w >= 0.0
+        })
+    }
+}
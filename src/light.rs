@@ -0,0 +1,37 @@
+use glam::Vec3;
+
+pub struct Light {
+    pub position: Vec3,
+    pub color: Vec3,
+}
+
+impl Default for Light {
+    fn default() -> Self {
+        Self {
+            position: Vec3::new(2.0, 2.0, 2.0),
+            color: Vec3::ONE,
+        }
+    }
+}
+
+impl Light {
+    pub fn uniform(&self) -> LightUniform {
+        LightUniform {
+            position: self.position.to_array(),
+            _pad: 0.0,
+            color: self.color.to_array(),
+            _pad2: 0.0,
+        }
+    }
+}
+
+/// GPU layout for the light uniform; `_pad`/`_pad2` mirror WGSL's 16-byte
+/// alignment for `vec3<f32>` members inside a uniform block.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightUniform {
+    pub position: [f32; 3],
+    pub _pad: f32,
+    pub color: [f32; 3],
+    pub _pad2: f32,
+}
@@ -1,12 +1,17 @@
 mod buffer_set;
 mod camera;
+mod frustum;
+mod instance;
+mod light;
 mod mesh;
 mod render_context;
+mod texture;
 mod vertex;
 
 use camera::Camera;
 use glam::Quat;
 use glam::Vec3;
+use light::Light;
 use render_context::RenderContext;
 
 use winit::{
@@ -21,6 +26,7 @@ struct App {
     window: Option<&'static Window>,
     render_context: Option<RenderContext>,
     camera: Camera,
+    light: Light,
     meshes: Vec<mesh::Mesh>,
 }
 
@@ -82,12 +88,10 @@ impl winit::application::ApplicationHandler<()> for App {
         }
         match event {
             WindowEvent::RedrawRequested => {
-                for mesh in &self.meshes {
-                    self.render_context
-                        .as_mut()
-                        .expect("Render Context not initialized")
-                        .render(&self.camera, mesh);
-                }
+                self.render_context
+                    .as_mut()
+                    .expect("Render Context not initialized")
+                    .render(&self.camera, &self.light, &self.meshes);
             }
             WindowEvent::Resized(size) => {
                 self.render_context
@@ -119,12 +123,18 @@ async fn run() {
         vec![
             vertex::Vertex {
                 position: [-0.5, -0.5, 1.0],
+                tex_coords: [0.0, 1.0],
+                normal: [0.0, 0.0, -1.0],
             },
             vertex::Vertex {
                 position: [0.5, -0.5, 1.0],
+                tex_coords: [1.0, 1.0],
+                normal: [0.0, 0.0, -1.0],
             },
             vertex::Vertex {
                 position: [0.0, 0.5, 1.0],
+                tex_coords: [0.5, 0.0],
+                normal: [0.0, 0.0, -1.0],
             },
         ],
         vec![0, 1, 2],
@@ -134,12 +144,18 @@ async fn run() {
         vec![
             vertex::Vertex {
                 position: [1.5, -0.5, 1.0],
+                tex_coords: [0.0, 1.0],
+                normal: [0.0, 0.0, -1.0],
             },
             vertex::Vertex {
                 position: [2.5, -0.5, 1.0],
+                tex_coords: [1.0, 1.0],
+                normal: [0.0, 0.0, -1.0],
             },
             vertex::Vertex {
                 position: [2.0, 0.5, 1.0],
+                tex_coords: [0.5, 0.0],
+                normal: [0.0, 0.0, -1.0],
             },
         ],
         vec![0, 1, 2],
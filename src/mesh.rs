@@ -3,22 +3,163 @@ use wgpu::util::DeviceExt;
 use crate::vertex::Vertex;
 
 use crate::buffer_set::BufferSet;
+use crate::frustum::Aabb;
+use crate::instance::InstanceRaw;
+use crate::texture::DEFAULT_TEXTURE_ID;
+use glam::{Mat4, Vec3};
+
+/// A mesh's index buffer contents, kept as `u16` for hand-authored geometry
+/// and promoted to `u32` for OBJ models with more than 65,535 vertices.
+pub enum Indices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl Indices {
+    fn len(&self) -> usize {
+        match self {
+            Indices::U16(indices) => indices.len(),
+            Indices::U32(indices) => indices.len(),
+        }
+    }
+
+    fn format(&self) -> wgpu::IndexFormat {
+        match self {
+            Indices::U16(_) => wgpu::IndexFormat::Uint16,
+            Indices::U32(_) => wgpu::IndexFormat::Uint32,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Indices::U16(indices) => bytemuck::cast_slice(indices),
+            Indices::U32(indices) => bytemuck::cast_slice(indices),
+        }
+    }
+}
 
 pub struct Mesh {
     id: uuid::Uuid,
     vertices: Vec<Vertex>,
-    indices: Vec<u16>,
+    indices: Indices,
+    instances: Vec<Mat4>,
+    texture_id: uuid::Uuid,
+    local_aabb: Aabb,
+}
+
+fn local_aabb(vertices: &[Vertex]) -> Aabb {
+    Aabb::from_points(vertices.iter().map(|vertex| Vec3::from(vertex.position)))
 }
 
 impl Mesh {
     pub fn new(vertices: Vec<Vertex>, indices: Vec<u16>) -> Self {
+        let local_aabb = local_aabb(&vertices);
+
         Self {
             vertices,
-            indices,
+            indices: Indices::U16(indices),
+            instances: vec![Mat4::IDENTITY],
+            texture_id: DEFAULT_TEXTURE_ID,
+            local_aabb,
             id: uuid::Uuid::new_v4(),
         }
     }
 
+    /// Loads a Wavefront `.obj` file into one `Mesh` per object, promoting
+    /// the index buffer to `u32` for any object with more than 65,535
+    /// vertices.
+    pub fn from_obj(path: impl AsRef<std::path::Path>) -> Vec<Mesh> {
+        let (models, _materials) = tobj::load_obj(
+            path.as_ref(),
+            &tobj::LoadOptions {
+                triangulate: true,
+                single_index: true,
+                ..Default::default()
+            },
+        )
+        .expect("Failed to load OBJ file");
+
+        models
+            .into_iter()
+            .map(|model| {
+                let mesh = model.mesh;
+                let vertex_count = mesh.positions.len() / 3;
+
+                let vertices = (0..vertex_count)
+                    .map(|i| Vertex {
+                        position: [
+                            mesh.positions[i * 3],
+                            mesh.positions[i * 3 + 1],
+                            mesh.positions[i * 3 + 2],
+                        ],
+                        tex_coords: if mesh.texcoords.is_empty() {
+                            [0.0, 0.0]
+                        } else {
+                            [mesh.texcoords[i * 2], 1.0 - mesh.texcoords[i * 2 + 1]]
+                        },
+                        normal: if mesh.normals.is_empty() {
+                            [0.0, 0.0, 1.0]
+                        } else {
+                            [
+                                mesh.normals[i * 3],
+                                mesh.normals[i * 3 + 1],
+                                mesh.normals[i * 3 + 2],
+                            ]
+                        },
+                    })
+                    .collect();
+
+                let indices = if vertex_count > u16::MAX as usize {
+                    Indices::U32(mesh.indices)
+                } else {
+                    Indices::U16(mesh.indices.iter().map(|&i| i as u16).collect())
+                };
+
+                Mesh {
+                    local_aabb: local_aabb(&vertices),
+                    vertices,
+                    indices,
+                    instances: vec![Mat4::IDENTITY],
+                    texture_id: DEFAULT_TEXTURE_ID,
+                    id: uuid::Uuid::new_v4(),
+                }
+            })
+            .collect()
+    }
+
+    /// Points this mesh at a texture previously registered with
+    /// `RenderContext::register_texture`. Meshes default to the renderer's
+    /// built-in white texture.
+    pub fn set_texture(&mut self, texture_id: uuid::Uuid) {
+        self.texture_id = texture_id;
+    }
+
+    pub fn texture_id(&self) -> uuid::Uuid {
+        self.texture_id
+    }
+
+    /// Replaces this mesh's instance transforms. Pass one `Mat4` per placement;
+    /// an empty mesh has a single identity instance by default.
+    pub fn set_instances(&mut self, instances: Vec<Mat4>) {
+        self.instances = instances;
+    }
+
+    pub fn instances(&self) -> &[Mat4] {
+        &self.instances
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// This mesh's bounding box, transformed into world space for each of
+    /// its instances, used by frustum culling.
+    pub fn world_aabbs(&self) -> impl Iterator<Item = Aabb> + '_ {
+        self.instances
+            .iter()
+            .map(|&transform| self.local_aabb.transformed(transform))
+    }
+
     pub fn buffer_set(&self, device: &wgpu::Device) -> BufferSet {
         let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Vertex Buffer"),
@@ -28,11 +169,24 @@ impl Mesh {
 
         let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Index Buffer"),
-            contents: bytemuck::cast_slice(&self.indices),
+            contents: self.indices.as_bytes(),
             usage: wgpu::BufferUsages::INDEX,
         });
 
-        BufferSet::new(index_buffer, vertex_buffer)
+        let instance_data: Vec<InstanceRaw> = self
+            .instances
+            .iter()
+            .map(|&model| InstanceRaw::from_matrix(model))
+            .collect();
+
+        let instance_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Instance Buffer"),
+            contents: bytemuck::cast_slice(&instance_data),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        BufferSet::new(index_buffer, vertex_buffer, self.indices.format())
+            .with_instance_buffer(instance_buffer)
     }
 
     pub fn index_count(&self) -> usize {
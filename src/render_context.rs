@@ -1,14 +1,20 @@
 use crate::Camera;
 use crate::buffer_set::BufferSet;
+use crate::camera::CameraUniform;
+use crate::frustum::Frustum;
+use crate::instance::InstanceRaw;
+use crate::light::Light;
+use crate::texture::{DEFAULT_TEXTURE_ID, Texture};
 use crate::vertex::Vertex;
 
 use crate::mesh::Mesh;
-use glam::Mat4;
 use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 use winit::window::Window;
 
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
 pub struct RenderContext {
     surface: Arc<wgpu::Surface<'static>>,
     surface_config: wgpu::SurfaceConfiguration,
@@ -18,6 +24,12 @@ pub struct RenderContext {
     uniform_buffer: wgpu::Buffer,
     uniform_bind_group: wgpu::BindGroup,
     buffer_sets: HashMap<Uuid, BufferSet>,
+    depth_texture: wgpu::Texture,
+    depth_view: wgpu::TextureView,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    textures: HashMap<Uuid, Texture>,
+    light_buffer: wgpu::Buffer,
+    light_bind_group: wgpu::BindGroup,
 }
 
 impl RenderContext {
@@ -57,7 +69,7 @@ impl RenderContext {
 
         let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Uniform Buffer"),
-            size: std::mem::size_of::<Mat4>() as wgpu::BufferAddress,
+            size: std::mem::size_of::<CameraUniform>() as wgpu::BufferAddress,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -67,7 +79,7 @@ impl RenderContext {
                 label: Some("Uniform Bind Group Layout"),
                 entries: &[wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
@@ -86,9 +98,67 @@ impl RenderContext {
             }],
         });
 
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Texture Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let light_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Light Buffer"),
+            size: std::mem::size_of::<crate::light::LightUniform>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let light_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Light Bind Group Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let light_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Light Bind Group"),
+            layout: &light_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: light_buffer.as_entire_binding(),
+            }],
+        });
+
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Pipeline Layout"),
-            bind_group_layouts: &[&uniform_bind_group_layout],
+            bind_group_layouts: &[
+                &uniform_bind_group_layout,
+                &texture_bind_group_layout,
+                &light_bind_group_layout,
+            ],
             push_constant_ranges: &[],
         });
 
@@ -103,7 +173,7 @@ impl RenderContext {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
-                buffers: &[Vertex::desc()],
+                buffers: &[Vertex::desc(), InstanceRaw::desc()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -117,12 +187,25 @@ impl RenderContext {
                 compilation_options: Default::default(),
             }),
             primitive: wgpu::PrimitiveState::default(),
-            depth_stencil: None,
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
             cache: None,
         });
 
+        let (depth_texture, depth_view) =
+            Self::create_depth_texture(&device, config.width, config.height);
+
+        let default_texture = Texture::from_color(&device, &queue, [255, 255, 255, 255], "Default Texture");
+        let mut textures = HashMap::new();
+        textures.insert(DEFAULT_TEXTURE_ID, default_texture);
+
         Self {
             surface,
             device,
@@ -132,21 +215,64 @@ impl RenderContext {
             uniform_buffer,
             uniform_bind_group,
             buffer_sets: HashMap::new(),
+            depth_texture,
+            depth_view,
+            texture_bind_group_layout,
+            textures,
+            light_buffer,
+            light_bind_group,
         }
     }
 
+    /// Registers a texture so meshes can reference it via `Mesh::set_texture`.
+    pub fn register_texture(&mut self, id: Uuid, texture: Texture) {
+        self.textures.insert(id, texture);
+    }
+
+    fn create_depth_texture(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+    ) -> (wgpu::Texture, wgpu::TextureView) {
+        let depth_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let depth_view = depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        (depth_texture, depth_view)
+    }
+
     pub fn register_mesh(&mut self, mesh: &Mesh) {
         if self.buffer_sets.contains_key(&mesh.id()) {
             return; // Mesh already registered
         }
 
-        let buffer_set = mesh.buffer_set(&self.device);
+        let mut buffer_set = mesh.buffer_set(&self.device);
+
+        if let Some(texture) = self.textures.get(&mesh.texture_id()) {
+            let texture_bind_group = texture.bind_group(&self.device, &self.texture_bind_group_layout);
+            buffer_set = buffer_set.with_texture_bind_group(texture_bind_group);
+        }
+
         self.buffer_sets.insert(mesh.id(), buffer_set);
     }
 
-    pub fn render(&mut self, camera: &Camera, mesh: &Mesh) {
-        if !self.buffer_sets.contains_key(&mesh.id()) {
-            self.register_mesh(mesh);
+    pub fn render(&mut self, camera: &Camera, light: &Light, meshes: &[Mesh]) {
+        for mesh in meshes {
+            if !self.buffer_sets.contains_key(&mesh.id()) {
+                self.register_mesh(mesh);
+            }
         }
 
         if self.surface_config.width == 0 || self.surface_config.height == 0 {
@@ -176,13 +302,14 @@ impl RenderContext {
         self.queue.write_buffer(
             &self.uniform_buffer,
             0,
-            bytemuck::cast_slice(
-                &camera
-                    .view_projection_matrix(aspect_ratio)
-                    .to_cols_array_2d(),
-            ),
+            bytemuck::cast_slice(&[CameraUniform::new(camera, aspect_ratio)]),
         );
 
+        self.queue
+            .write_buffer(&self.light_buffer, 0, bytemuck::cast_slice(&[light.uniform()]));
+
+        let frustum = Frustum::from_view_proj(camera.view_projection_matrix(aspect_ratio));
+
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Render Pass"),
@@ -195,24 +322,50 @@ impl RenderContext {
                     },
                     depth_slice: None,
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &self.depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
                 timestamp_writes: None,
                 occlusion_query_set: None,
             });
 
             render_pass.set_pipeline(&self.render_pipeline);
-
-            // Set vertex/index buffers
-            let buffer_set = self.buffer_sets.get(&mesh.id()).unwrap();
-            render_pass.set_vertex_buffer(0, buffer_set.vertex_buffer().slice(..));
-            render_pass.set_index_buffer(
-                buffer_set.index_buffer().slice(..),
-                wgpu::IndexFormat::Uint16,
-            );
-
             render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
-
-            render_pass.draw_indexed(0..mesh.index_count() as u32, 0, 0..1);
+            render_pass.set_bind_group(2, &self.light_bind_group, &[]);
+
+            for mesh in meshes {
+                let visible = mesh
+                    .world_aabbs()
+                    .any(|aabb| frustum.intersects_aabb(&aabb));
+                if !visible {
+                    continue;
+                }
+
+                let buffer_set = self.buffer_sets.get(&mesh.id()).unwrap();
+                render_pass.set_vertex_buffer(0, buffer_set.vertex_buffer().slice(..));
+                render_pass.set_vertex_buffer(
+                    1,
+                    buffer_set
+                        .instance_buffer()
+                        .expect("mesh was registered without an instance buffer")
+                        .slice(..),
+                );
+                render_pass.set_index_buffer(buffer_set.index_buffer().slice(..), buffer_set.index_format());
+                render_pass.set_bind_group(
+                    1,
+                    buffer_set
+                        .texture_bind_group()
+                        .expect("mesh was registered without a texture bind group"),
+                    &[],
+                );
+
+                render_pass.draw_indexed(0..mesh.index_count() as u32, 0, 0..mesh.instance_count() as u32);
+            }
         }
 
         self.queue.submit(std::iter::once(encoder.finish()));
@@ -228,5 +381,9 @@ impl RenderContext {
             return; // Ignore zero-sized windows
         }
         self.surface.configure(&self.device, &self.surface_config);
+
+        let (depth_texture, depth_view) = Self::create_depth_texture(&self.device, width, height);
+        self.depth_texture = depth_texture;
+        self.depth_view = depth_view;
     }
 }